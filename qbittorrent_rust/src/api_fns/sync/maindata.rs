@@ -0,0 +1,223 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use futures_core::Stream;
+use reqwest::header;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::Mutex;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    core::api::QbitApi,
+    error_handling::{error_type::ErrorType, errors::Error},
+};
+
+/// ## Info
+/// a torrent's fields as reported by `/api/v2/sync/maindata`, keyed by field name.
+/// on a partial update this only holds the fields that actually changed.
+pub type TorrentFields = HashMap<String, Value>;
+
+/// ## Info
+/// an event yielded by the stream returned by [`QbitApi::sync_maindata_stream`].
+#[derive(Debug, Clone)]
+pub enum MainDataEvent {
+    /// a torrent wasn't in the local mirror yet; carries its hash and full known state.
+    Added { hash: String, state: TorrentFields },
+
+    /// a torrent already in the local mirror changed; carries its hash and only the fields the server sent.
+    Updated { hash: String, changed_fields: TorrentFields },
+
+    /// a torrent was removed.
+    Removed { hash: String },
+
+    /// the response carried a `server_state`, `categories` or `tags` section (always true on a
+    /// full update, sometimes true on a partial one); the caller should re-read whatever of those
+    /// it cares about. yielded alongside, not instead of, the per-torrent events above.
+    ServerStateChanged,
+}
+
+#[derive(Debug, Deserialize)]
+struct MainDataResponse {
+    rid: u64,
+    #[serde(default)]
+    full_update: bool,
+    #[serde(default)]
+    torrents: HashMap<String, TorrentFields>,
+    #[serde(default)]
+    torrents_removed: Vec<String>,
+    #[serde(default)]
+    server_state: Option<Value>,
+    #[serde(default)]
+    categories: Option<Value>,
+    #[serde(default)]
+    tags: Option<Value>,
+}
+impl MainDataResponse {
+    /// whether this (partial) response touched anything outside `torrents`/`torrents_removed`.
+    fn has_server_state_change(&self) -> bool {
+        self.server_state.is_some() || self.categories.is_some() || self.tags.is_some()
+    }
+}
+
+impl QbitApi {
+    /// ## Usage
+    /// returns a [`Stream`] of [`MainDataEvent`]s, polling `GET /api/v2/sync/maindata` every `interval`.
+    ///
+    /// takes `qbit` behind an `Arc<Mutex<_>>` rather than `&mut self`: the poll loop runs in its own
+    /// background task and only locks `qbit` for the duration of a single request, so the returned
+    /// stream can be held open for the lifetime of a long-running client while `qbit` is still free
+    /// to be used for other requests (e.g. [`QbitApi::torrents_add_torrent`]) in between ticks.
+    ///
+    /// keeps a client-side `rid` counter and a hash → fields mirror. qBittorrent always answers the
+    /// first `rid=0` request with a full update, and can resend one any time it drops the client's
+    /// diff history (reconnects, long gaps), so a full update is diffed against the existing mirror
+    /// - rather than collapsed into a single opaque signal - to still yield per-hash `Added`/`Updated`/
+    /// `Removed` events for whatever actually changed. on a partial update each torrent's delta is
+    /// merged field-by-field into the existing entry instead, and hashes listed in `torrents_removed`
+    /// are dropped. this lets callers react to torrents being added, updated or removed without
+    /// hand-rolling their own polling loop.
+    ///
+    /// ## Warning
+    /// a field missing from a delta means "unchanged", not "null" - [`MainDataEvent::Updated::changed_fields`]
+    /// only ever contains the fields qBittorrent actually sent for that tick.
+    pub fn sync_maindata_stream(
+        qbit: Arc<Mutex<QbitApi>>,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<MainDataEvent, Error>> {
+        let (tx, rx) = tokio::sync::mpsc::channel(32);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            let mut rid = 0u64;
+            let mut torrents: HashMap<String, TorrentFields> = HashMap::new();
+
+            loop {
+                ticker.tick().await;
+
+                let response = qbit.lock().await.request_maindata(rid).await;
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                };
+
+                rid = response.rid;
+
+                if response.full_update {
+                    let new_torrents = response.torrents;
+                    let old_hashes: HashSet<&String> = torrents.keys().collect();
+                    let new_hashes: HashSet<&String> = new_torrents.keys().collect();
+
+                    for &hash in old_hashes.difference(&new_hashes) {
+                        let hash = hash.clone();
+                        if tx.send(Ok(MainDataEvent::Removed { hash })).await.is_err() {
+                            return;
+                        }
+                    }
+
+                    for &hash in new_hashes.difference(&old_hashes) {
+                        let state = new_torrents[hash].clone();
+                        let hash = hash.clone();
+                        if tx.send(Ok(MainDataEvent::Added { hash, state })).await.is_err() {
+                            return;
+                        }
+                    }
+
+                    for &hash in old_hashes.intersection(&new_hashes) {
+                        let old_state = &torrents[hash];
+                        let new_state = &new_torrents[hash];
+
+                        let changed_fields: TorrentFields = new_state
+                            .iter()
+                            .filter(|(field, value)| old_state.get(*field) != Some(*value))
+                            .map(|(field, value)| (field.clone(), value.clone()))
+                            .collect();
+
+                        if !changed_fields.is_empty() {
+                            let hash = hash.clone();
+                            if tx.send(Ok(MainDataEvent::Updated { hash, changed_fields })).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+
+                    torrents = new_torrents;
+
+                    if tx.send(Ok(MainDataEvent::ServerStateChanged)).await.is_err() {
+                        return;
+                    }
+                    continue;
+                }
+
+                let server_state_changed = response.has_server_state_change();
+
+                for hash in response.torrents_removed {
+                    torrents.remove(&hash);
+                    if tx.send(Ok(MainDataEvent::Removed { hash })).await.is_err() {
+                        return;
+                    }
+                }
+
+                for (hash, changed_fields) in response.torrents {
+                    let event = match torrents.get_mut(&hash) {
+                        Some(state) => {
+                            for (field, value) in changed_fields.iter() {
+                                state.insert(field.clone(), value.clone());
+                            }
+                            MainDataEvent::Updated { hash, changed_fields }
+                        }
+                        None => {
+                            torrents.insert(hash.clone(), changed_fields.clone());
+                            MainDataEvent::Added { hash, state: changed_fields }
+                        }
+                    };
+
+                    if tx.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+
+                if server_state_changed && tx.send(Ok(MainDataEvent::ServerStateChanged)).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+
+    async fn request_maindata(&mut self, rid: u64) -> Result<MainDataResponse, Error> {
+        let response = self
+            .send_authed(|api| {
+                Box::pin(async move {
+                    api.reqwest_client
+                        .get(format!("{}/api/v2/sync/maindata", api.authority))
+                        .query(&[("rid", rid.to_string())])
+                        .header(header::COOKIE, format!("SID={}", api.get_cookie().await?))
+                        .send()
+                        .await
+                        .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))
+                })
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let code = response.status().as_u16();
+            return Err(Error::build(ErrorType::MiscNetError(code), Some(code)));
+        }
+
+        response
+            .json::<MainDataResponse>()
+            .await
+            .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))
+    }
+}
@@ -11,6 +11,50 @@ use crate::{
 
 use super::torrents::Torrent;
 
+/// ## Info
+/// the layout torrent content is placed in, replacing the stringly-typed `root_folder` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentLayout {
+    /// keep the layout as described by the torrent's metadata.
+    Original,
+    /// always create a subfolder.
+    Subfolder,
+    /// never create a subfolder.
+    NoSubfolder,
+}
+impl std::fmt::Display for ContentLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ContentLayout::Original => "Original",
+            ContentLayout::Subfolder => "Subfolder",
+            ContentLayout::NoSubfolder => "NoSubfolder",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// ## Info
+/// the condition at which a torrent should automatically be stopped after being added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopCondition {
+    /// don't stop the torrent automatically.
+    None,
+    /// stop as soon as the torrent's metadata has been received.
+    MetadataReceived,
+    /// stop as soon as all files have been checked.
+    FilesChecked,
+}
+impl std::fmt::Display for StopCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StopCondition::None => "None",
+            StopCondition::MetadataReceived => "MetadataReceived",
+            StopCondition::FilesChecked => "FilesChecked",
+        };
+        write!(f, "{s}")
+    }
+}
+
 /// ## Info
 /// struct that describes the adding of a torrent.
 /// create a new [`TorrentAddDescriptor`] by either:
@@ -22,6 +66,8 @@ pub struct TorrentAddDescriptor {
 
     paths: Vec<String>,
 
+    blobs: Vec<Vec<u8>>,
+
     /// Download folder path
     savepath: Option<String>,
 
@@ -66,6 +112,24 @@ pub struct TorrentAddDescriptor {
 
     /// Prioritize first and last piece download (true, false)
     first_last_piece_prio: Option<bool>,
+
+    /// Download folder for incomplete torrents, moved to `savepath` on completion
+    download_path: Option<String>,
+
+    /// Whether to use `download_path` for incomplete torrents
+    use_download_path: Option<bool>,
+
+    /// Torrent content layout
+    content_layout: Option<ContentLayout>,
+
+    /// Condition under which the torrent should automatically be stopped
+    stop_condition: Option<StopCondition>,
+
+    /// Add the torrent to the top of the queue
+    add_to_top_of_queue: Option<bool>,
+
+    /// Seeding time limit, in minutes, after which a torrent is considered inactive (since qBittorrent v4.6.0)
+    inactive_seeding_time_limit: Option<i64>,
 }
 impl TorrentAddDescriptor {
     /// ## Usage
@@ -116,6 +180,12 @@ impl TorrentAddDescriptor {
 /// | `autoTMM`        | `Bool`    | Whether Automatic Torrent Management should be used. (default: false)                        |
 /// | `sequentialDownload`  |  `Bool`  | Enable sequential download. (default: false) |
 /// | `firstLastPiecePrio`  |  `Bool`  | Prioritize download first last piece. (default: false) |
+/// | `downloadPath`   |  `String`  | Download folder for incomplete torrents. |
+/// | `useDownloadPath`  |  `Bool`  | Whether to use `downloadPath`. |
+/// | `contentLayout`  | [`ContentLayout`] | Torrent content layout. |
+/// | `stopCondition`  | [`StopCondition`] | Condition to automatically stop the torrent at. |
+/// | `addToTopOfQueue`  |  `Bool`  | Whether to add the torrent to the top of the queue. |
+/// | `inactiveSeedingTimeLimit`  | `Integer` | Seeding time limit, in minutes, after which the torrent is considered inactive. |
 #[derive(Debug, Clone, Builder)]
 pub struct TorrentAddDescriptorBuilder {
     #[builder(custom)]
@@ -165,6 +235,24 @@ pub struct TorrentAddDescriptorBuilder {
 
     /// Prioritize first and last piece download (true, false)
     first_last_piece_prio: Option<bool>,
+
+    /// Download folder for incomplete torrents, moved to `savepath` on completion
+    download_path: Option<String>,
+
+    /// Whether to use `download_path` for incomplete torrents
+    use_download_path: Option<bool>,
+
+    /// Torrent content layout
+    content_layout: Option<ContentLayout>,
+
+    /// Condition under which the torrent should automatically be stopped
+    stop_condition: Option<StopCondition>,
+
+    /// Add the torrent to the top of the queue
+    add_to_top_of_queue: Option<bool>,
+
+    /// Seeding time limit, in minutes, after which a torrent is considered inactive (since qBittorrent v4.6.0)
+    inactive_seeding_time_limit: Option<i64>,
 }
 impl TorrentAddDescriptorBuilder {
     ///## Info 
@@ -191,6 +279,12 @@ impl TorrentAddDescriptorBuilder {
             auto_tmm: None,
             sequential_download: None,
             first_last_piece_prio: None,
+            download_path: None,
+            use_download_path: None,
+            content_layout: None,
+            stop_condition: None,
+            add_to_top_of_queue: None,
+            inactive_seeding_time_limit: None,
         }
     }
 
@@ -200,26 +294,33 @@ impl TorrentAddDescriptorBuilder {
     /// ## Errors
     /// - if the `torrent`s vector was set as empty, it will return an [`Error`] with error type [`ErrorType::TorrentsNotSet`].
     pub fn build(self) -> Result<TorrentAddDescriptor, Error> {
-        let (urls, paths) = match self.torrents {
+        let (urls, paths, blobs) = match self.torrents {
             Some(t) => {
                 if t.is_empty() {
                     return Err(Error::build(ErrorType::TorrentsNotSet, None));
                 } else {
                     let mut vec_urls = vec![];
                     let mut vec_paths = vec![];
+                    let mut vec_blobs = vec![];
 
-                    for item in t.iter().map(|l| l.get_inner()) {
+                    for item in t.into_iter().map(|l| l.into_inner()) {
                         match item {
                             crate::api_fns::torrents::torrents::TorrentInner::Url(url) => {
                                 vec_urls.push(url)
                             }
+                            crate::api_fns::torrents::torrents::TorrentInner::Magnet(magnet) => {
+                                vec_urls.push(magnet)
+                            }
                             crate::api_fns::torrents::torrents::TorrentInner::RawTorrent(path) => {
                                 vec_paths.push(path)
                             }
+                            crate::api_fns::torrents::torrents::TorrentInner::RawBytes(bytes) => {
+                                vec_blobs.push(bytes)
+                            }
                         }
                     }
 
-                    (SepVec::new(vec_urls, "".to_string()), vec_paths)
+                    (SepVec::new(vec_urls, "".to_string()), vec_paths, vec_blobs)
                 }
             }
             None => {
@@ -238,6 +339,7 @@ impl TorrentAddDescriptorBuilder {
         Ok(TorrentAddDescriptor {
             urls,
             paths,
+            blobs,
             savepath: self.savepath,
             cookie: self.cookie,
             category: self.category,
@@ -253,6 +355,12 @@ impl TorrentAddDescriptorBuilder {
             auto_tmm: self.auto_tmm,
             sequential_download: self.sequential_download,
             first_last_piece_prio: self.first_last_piece_prio,
+            download_path: self.download_path,
+            use_download_path: self.use_download_path,
+            content_layout: self.content_layout,
+            stop_condition: self.stop_condition,
+            add_to_top_of_queue: self.add_to_top_of_queue,
+            inactive_seeding_time_limit: self.inactive_seeding_time_limit,
         })
     }
 }
@@ -263,115 +371,46 @@ impl QbitApi {
     pub async fn torrents_add_torrent(&mut self, descriptor: impl Borrow<TorrentAddDescriptor>) -> Result<(), Error> {
         let descriptor = descriptor.borrow();
 
-        match (
-            descriptor.paths.is_empty(),
-            descriptor.urls.inner_vec().is_empty(),
-        ) {
-            (true, true) => panic!(),
-            (true, false) => {
-                let mut form_urls = reqwest::multipart::Form::new();
-
-                form_urls = form_urls.text("urls", descriptor.urls.to_string());
-
-                form_urls = thing(form_urls, descriptor.clone());
-
-                let response_urls = self
-                    .reqwest_client
-                    .post(format!("{}/api/v2/torrents/add", self.authority))
-                    .multipart(form_urls)
-                    .header(header::COOKIE, format!("SID={}", self.get_cookie().await?))
-                    .send()
-                    .await
-                    .map_err(|e| {
-                        Error::build(ErrorType::ReqwestError(Box::new(e)), None)
-                    })?;
-
-                if response_urls.status().is_success() {
-                    return Ok(());
-                } else {
-                    return Err(Error::build(ErrorType::MiscNetError(code!(response_urls).unwrap()), code!(response_urls)));
-                }
-            }
-            (false, true) => {
-                let form = torrents_part(&descriptor).await?;
-
-                let response_torrents = self
-                    .reqwest_client
-                    .post(format!("{}/api/v2/torrents/add", self.authority))
-                    .multipart(form)
-                    .header(header::COOKIE, format!("SID={}", self.get_cookie().await?))
-                    .send()
-                    .await
-                    .map_err(|e| {
-                        Error::build(ErrorType::ReqwestError(Box::new(e)), None)
-                    })?;
-
-                if response_torrents.status().is_success() {
-                    return Ok(());
-                } else {
-                    return Err(Error::build(ErrorType::MiscNetError(code!(response_torrents).unwrap()), code!(response_torrents)));
-                }
-            }
-
-            (false, false) => {
-                // ---------- TORRENT FILES ----------
-                let form_torrents = torrents_part(&descriptor).await?;
-
-                let built_torrents = self
-                    .reqwest_client
-                    .post(format!("{}/api/v2/torrents/add", self.authority))
-                    .multipart(form_torrents)
-                    .header(header::COOKIE, format!("SID={}", self.get_cookie().await?));
-
-                // ---------- TORRENT FILES ----------
+        let has_files = !descriptor.paths.is_empty() || !descriptor.blobs.is_empty();
+        let has_urls = !descriptor.urls.inner_vec().is_empty();
 
-                // ---------- URLS ----------
-                let mut form_urls = reqwest::multipart::Form::new();
-
-                form_urls = form_urls.text("urls", descriptor.urls.to_string());
-                form_urls = thing(form_urls, descriptor.clone());
-
-                let built_urls = self
-                    .reqwest_client
-                    .post(format!("{}/api/v2/torrents/add", self.authority))
-                    .multipart(form_urls)
-                    .header(header::COOKIE, format!("SID={}", self.get_cookie().await?));
-
-                // ---------- URLS ----------
-
-                let (response_torrents, response_urls) =
-                    tokio::join!(built_torrents.send(), built_urls.send());
-
-                let mut thing = (false, false);
-
-                if response_torrents
-                    .map_err(|e| {
-                        Error::build(ErrorType::ReqwestError(Box::new(e)), None)
-                    })?
-                    .status()
-                    .is_success()
-                {
-                    thing.0 = true;
-                }
-
-                if response_urls
-                    .map_err(|e| {
-                        Error::build(ErrorType::ReqwestError(Box::new(e)), None)
-                    })?
-                    .status()
-                    .is_success()
-                {
-                    thing.1 = true
-                }
+        if !has_files && !has_urls {
+            panic!()
+        }
 
-                match thing {
-                        (true, true) => return Ok(()),
-                        (true, false) => return Err(Error::build(ErrorType::MiscError("something went wrong while adding urls.".to_string()), None)),
-                        (false, true) => return Err(Error::build(ErrorType::MiscError("something went wrong while adding torrent files.".to_string()), None)),
-                        (false, false) => return Err(Error::build(ErrorType::MiscError("wow, you really messed up. both torrents and urls failed.".to_string()), None)),
+        // torrent files and urls/magnets are both parts of the same multipart body, so
+        // there's no need for two separate requests: build one form and send it once.
+        // rebuilt fresh on every attempt, so a 403 can be retried after re-logging in.
+        // `send_authed` needs a future that only borrows the api, so each attempt works on
+        // its own clone of `descriptor`, which `thing` below then consumes.
+        let response = self
+            .send_authed(|api| {
+                let descriptor = descriptor.clone();
+                Box::pin(async move {
+                    let mut form = torrents_part(&descriptor).await?;
+
+                    if !descriptor.urls.inner_vec().is_empty() {
+                        form = form.text("urls", descriptor.urls.to_string());
                     }
-            }
-        };
+
+                    form = thing(form, descriptor);
+
+                    api.reqwest_client
+                        .post(format!("{}/api/v2/torrents/add", api.authority))
+                        .multipart(form)
+                        .header(header::COOKIE, format!("SID={}", api.get_cookie().await?))
+                        .send()
+                        .await
+                        .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))
+                })
+            })
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(Error::build(ErrorType::MiscNetError(code!(response).unwrap()), code!(response)))
+        }
     }
 }
 
@@ -439,6 +478,30 @@ fn thing(
         form = form.text("firstLastPiecePrio", first_last_piece_prio.to_string());
     }
 
+    if let Some(download_path) = descriptor.download_path {
+        form = form.text("downloadPath", download_path);
+    }
+
+    if let Some(use_download_path) = descriptor.use_download_path {
+        form = form.text("useDownloadPath", use_download_path.to_string());
+    }
+
+    if let Some(content_layout) = descriptor.content_layout {
+        form = form.text("contentLayout", content_layout.to_string());
+    }
+
+    if let Some(stop_condition) = descriptor.stop_condition {
+        form = form.text("stopCondition", stop_condition.to_string());
+    }
+
+    if let Some(add_to_top_of_queue) = descriptor.add_to_top_of_queue {
+        form = form.text("addToTopOfQueue", add_to_top_of_queue.to_string());
+    }
+
+    if let Some(inactive_seeding_time_limit) = descriptor.inactive_seeding_time_limit {
+        form = form.text("inactiveSeedingTimeLimit", inactive_seeding_time_limit.to_string());
+    }
+
     form
 }
 
@@ -446,8 +509,9 @@ async fn torrents_part(
     descriptor: &TorrentAddDescriptor,
 ) -> Result<reqwest::multipart::Form, Error> {
     let mut form_torrents = reqwest::multipart::Form::new();
+
     for path in descriptor.paths.clone() {
-        let mut file = File::open(path)
+        let mut file = File::open(&path)
             .await
             .map_err(|_| Error::build(ErrorType::TorrentFilePathError, None))?;
 
@@ -457,13 +521,28 @@ async fn torrents_part(
             .await
             .map_err(|_| Error::build(ErrorType::TorrentFilePathError, None))?;
 
-        // part 4 the multipart form
+        // derive the file name from the path's basename so multiple files don't clobber each other
+        let file_name = std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "torrent_file.torrent".to_string());
+
         let file_part = reqwest::multipart::Part::bytes(buffer)
-            .file_name("torrent_file.torrent")
+            .file_name(file_name)
+            .mime_str("application/x-bittorrent")
+            .unwrap();
+
+        form_torrents = form_torrents.part("torrents", file_part);
+    }
+
+    for (index, blob) in descriptor.blobs.iter().enumerate() {
+        let file_part = reqwest::multipart::Part::bytes(blob.clone())
+            .file_name(format!("torrent_{index}.torrent"))
             .mime_str("application/x-bittorrent")
             .unwrap();
 
         form_torrents = form_torrents.part("torrents", file_part);
     }
+
     Ok(form_torrents)
 }
\ No newline at end of file
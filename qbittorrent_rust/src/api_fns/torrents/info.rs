@@ -0,0 +1,252 @@
+use std::{borrow::Borrow, collections::HashMap};
+
+use proc_macros_qbittorrent_rust::Builder;
+use reqwest::header;
+use serde_json::Value;
+
+use crate::{
+    code, core::api::QbitApi, error_handling::{error_type::ErrorType, errors::Error}, misc::sep_vec::SepVec,
+};
+
+/// ## Info
+/// a torrent's full field set, as returned per-entry by `/api/v2/torrents/info`.
+/// unlike [`crate::api_fns::sync::maindata::TorrentFields`], this always holds every field - there's
+/// no partial-update concept here.
+pub type TorrentInfo = HashMap<String, Value>;
+
+/// ## Info
+/// which subset of torrents `/api/v2/torrents/info` should return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TorrentFilter {
+    All,
+    Downloading,
+    Seeding,
+    Completed,
+    Paused,
+    Active,
+    Inactive,
+    Stalled,
+    Errored,
+}
+impl std::fmt::Display for TorrentFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TorrentFilter::All => "all",
+            TorrentFilter::Downloading => "downloading",
+            TorrentFilter::Seeding => "seeding",
+            TorrentFilter::Completed => "completed",
+            TorrentFilter::Paused => "paused",
+            TorrentFilter::Active => "active",
+            TorrentFilter::Inactive => "inactive",
+            TorrentFilter::Stalled => "stalled",
+            TorrentFilter::Errored => "errored",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// ## Info
+/// the torrent property to sort the list by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TorrentSort {
+    Name,
+    Size,
+    Progress,
+    DlSpeed,
+    UpSpeed,
+    Priority,
+    NumSeeds,
+    NumLeechs,
+    Ratio,
+    Eta,
+    State,
+    Category,
+    Tags,
+    AddedOn,
+    CompletionOn,
+    Tracker,
+}
+impl std::fmt::Display for TorrentSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TorrentSort::Name => "name",
+            TorrentSort::Size => "size",
+            TorrentSort::Progress => "progress",
+            TorrentSort::DlSpeed => "dlspeed",
+            TorrentSort::UpSpeed => "upspeed",
+            TorrentSort::Priority => "priority",
+            TorrentSort::NumSeeds => "num_seeds",
+            TorrentSort::NumLeechs => "num_leechs",
+            TorrentSort::Ratio => "ratio",
+            TorrentSort::Eta => "eta",
+            TorrentSort::State => "state",
+            TorrentSort::Category => "category",
+            TorrentSort::Tags => "tags",
+            TorrentSort::AddedOn => "added_on",
+            TorrentSort::CompletionOn => "completion_on",
+            TorrentSort::Tracker => "tracker",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// ## Info
+/// struct that describes a query to [`QbitApi::torrents_info`].
+/// create a new [`TorrentListRequest`] by either:
+/// - using the `new` function, for the default (unfiltered) listing;
+/// - using the `builder` function.
+#[derive(Debug, Clone)]
+pub struct TorrentListRequest {
+    filter: Option<TorrentFilter>,
+    category: Option<String>,
+    tag: Option<String>,
+    sort: Option<TorrentSort>,
+    reverse: Option<bool>,
+    limit: Option<u64>,
+    offset: Option<i64>,
+    hashes: Option<SepVec<String, char>>,
+}
+impl TorrentListRequest {
+    /// ## Usage
+    /// creates a new, unfiltered [`TorrentListRequest`].
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// ## Usage
+    /// returns a new instance of [`TorrentListRequestBuilder`]: the builder for [`TorrentListRequest`].
+    pub fn builder() -> TorrentListRequestBuilder {
+        TorrentListRequestBuilder::new()
+    }
+}
+impl Default for TorrentListRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ## Info
+/// the builder struct for [`TorrentListRequest`].
+/// ## Usage
+/// call its methods and set the various fields.
+/// Once you're done, call `build()`
+///
+/// ## Fields:
+/// | Property   | Type                | Description                                              |
+/// |------------|---------------------|-----------------------------------------------------------|
+/// | `filter`   | [`TorrentFilter`]   | Only list torrents matching this filter.                  |
+/// | `category` | `String`            | Only list torrents in this category.                       |
+/// | `tag`      | `String`            | Only list torrents with this tag.                          |
+/// | `sort`     | [`TorrentSort`]     | Sort the list by this property.                            |
+/// | `reverse`  | `Bool`              | Reverse the sorting order.                                 |
+/// | `limit`    | `Integer`           | Limit the number of torrents returned.                     |
+/// | `offset`   | `Integer`           | Skip this many torrents from the start of the list.        |
+/// | `hashes`   | `Vec<String>`       | Only list torrents with one of these hashes.                |
+#[derive(Debug, Clone, Builder)]
+pub struct TorrentListRequestBuilder {
+    filter: Option<TorrentFilter>,
+    category: Option<String>,
+    tag: Option<String>,
+    sort: Option<TorrentSort>,
+    reverse: Option<bool>,
+    limit: Option<u64>,
+    offset: Option<i64>,
+    hashes: Option<Vec<String>>,
+}
+impl TorrentListRequestBuilder {
+    /// ## Usage
+    /// creates a new instance of [`TorrentListRequestBuilder`].
+    pub fn new() -> Self {
+        Self {
+            filter: None,
+            category: None,
+            tag: None,
+            sort: None,
+            reverse: None,
+            limit: None,
+            offset: None,
+            hashes: None,
+        }
+    }
+
+    /// ## Usage
+    /// returns the finalized [`TorrentListRequest`].
+    pub fn build(self) -> TorrentListRequest {
+        TorrentListRequest {
+            filter: self.filter,
+            category: self.category,
+            tag: self.tag,
+            sort: self.sort,
+            reverse: self.reverse,
+            limit: self.limit,
+            offset: self.offset,
+            hashes: self.hashes.map(|hashes| SepVec::new(hashes, '|')),
+        }
+    }
+}
+
+impl QbitApi {
+    /// ## Usage
+    /// lists torrents, filtered and sorted according to `request`.
+    pub async fn torrents_info(&mut self, request: impl Borrow<TorrentListRequest>) -> Result<Vec<TorrentInfo>, Error> {
+        let request = request.borrow();
+
+        let mut query = vec![];
+
+        if let Some(filter) = request.filter {
+            query.push(("filter".to_string(), filter.to_string()));
+        }
+
+        if let Some(category) = &request.category {
+            query.push(("category".to_string(), category.clone()));
+        }
+
+        if let Some(tag) = &request.tag {
+            query.push(("tag".to_string(), tag.clone()));
+        }
+
+        if let Some(sort) = request.sort {
+            query.push(("sort".to_string(), sort.to_string()));
+        }
+
+        if let Some(reverse) = request.reverse {
+            query.push(("reverse".to_string(), reverse.to_string()));
+        }
+
+        if let Some(limit) = request.limit {
+            query.push(("limit".to_string(), limit.to_string()));
+        }
+
+        if let Some(offset) = request.offset {
+            query.push(("offset".to_string(), offset.to_string()));
+        }
+
+        if let Some(hashes) = &request.hashes {
+            query.push(("hashes".to_string(), hashes.to_string()));
+        }
+
+        let response = self
+            .send_authed(|api| {
+                let query = query.clone();
+                Box::pin(async move {
+                    api.reqwest_client
+                        .get(format!("{}/api/v2/torrents/info", api.authority))
+                        .query(&query)
+                        .header(header::COOKIE, format!("SID={}", api.get_cookie().await?))
+                        .send()
+                        .await
+                        .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))
+                })
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(Error::build(ErrorType::MiscNetError(code!(response).unwrap()), code!(response)));
+        }
+
+        response
+            .json::<Vec<TorrentInfo>>()
+            .await
+            .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))
+    }
+}
@@ -0,0 +1,54 @@
+/// ## Info
+/// represents a single torrent to add, built from one of its constructors.
+/// used as the building block for [`crate::TorrentAddDescriptor`].
+#[derive(Debug, Clone)]
+pub struct Torrent(TorrentInner);
+
+impl Torrent {
+    /// ## Usage
+    /// builds a [`Torrent`] from an http(s) url pointing to a `.torrent` file.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self(TorrentInner::Url(url.into()))
+    }
+
+    /// ## Usage
+    /// builds a [`Torrent`] from the path to a `.torrent` file on disk.
+    pub fn path(path: impl Into<String>) -> Self {
+        Self(TorrentInner::RawTorrent(path.into()))
+    }
+
+    /// ## Usage
+    /// builds a [`Torrent`] from the raw bytes of a `.torrent` file, for callers who
+    /// already hold its contents (e.g. downloaded with their own HTTP client) and don't
+    /// want to round-trip them through the filesystem.
+    pub fn bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self(TorrentInner::RawBytes(bytes.into()))
+    }
+
+    /// ## Usage
+    /// builds a [`Torrent`] from a magnet link.
+    pub fn magnet(magnet: impl Into<String>) -> Self {
+        Self(TorrentInner::Magnet(magnet.into()))
+    }
+
+    pub(crate) fn into_inner(self) -> TorrentInner {
+        self.0
+    }
+}
+
+/// ## Info
+/// the source a [`Torrent`] was built from.
+#[derive(Debug, Clone)]
+pub enum TorrentInner {
+    /// an http(s) url pointing to a `.torrent` file.
+    Url(String),
+
+    /// the path to a `.torrent` file on disk.
+    RawTorrent(String),
+
+    /// the raw bytes of a `.torrent` file, already held in memory.
+    RawBytes(Vec<u8>),
+
+    /// a magnet link.
+    Magnet(String),
+}
@@ -0,0 +1,233 @@
+use std::{
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::error_handling::{error_type::ErrorType, errors::Error};
+
+/// ## Info
+/// the url/credentials [`QbitApi`] logged in with, kept around so the session can be
+/// transparently re-established if the server ever invalidates the cookie.
+#[derive(Debug, Clone)]
+pub(crate) struct ConnectionInfo {
+    pub(crate) url: String,
+    pub(crate) username: String,
+    pub(crate) password: String,
+}
+
+/// ## Info
+/// a saved session cookie, along with the time it was issued.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub cookie: String,
+    pub issued_at: u64,
+}
+
+/// ## Info
+/// lets [`QbitApi`] save and reload its session cookie across process restarts, so a
+/// long-running client doesn't have to log in again every time it starts up.
+///
+/// ## Warning
+/// `save`/`load` are called from async code (`relogin`, `login_with_persistence`) via
+/// `tokio::task::spawn_blocking`, so they're free to do blocking I/O - but keep them reasonably
+/// fast regardless, since `spawn_blocking` still ties up a thread from the blocking pool for as
+/// long as they run.
+pub trait SessionPersistence: std::fmt::Debug + Send + Sync {
+    /// persists `session` so a later [`SessionPersistence::load`] can retrieve it.
+    fn save(&self, session: &SavedSession) -> Result<(), Error>;
+
+    /// retrieves a previously saved session, if any.
+    fn load(&self) -> Result<Option<SavedSession>, Error>;
+}
+
+/// ## Info
+/// a [`SessionPersistence`] that stores the session as JSON in a file on disk.
+#[derive(Debug, Clone)]
+pub struct JsonFileSessionPersistence {
+    path: PathBuf,
+}
+impl JsonFileSessionPersistence {
+    /// ## Usage
+    /// creates a new [`JsonFileSessionPersistence`] that reads/writes the session at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+impl SessionPersistence for JsonFileSessionPersistence {
+    fn save(&self, session: &SavedSession) -> Result<(), Error> {
+        let contents = serde_json::to_string(session)
+            .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))?;
+
+        std::fs::write(&self.path, contents)
+            .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))?;
+
+        // the saved cookie is a bearer credential for the WebUI, so make sure only its
+        // owner can read it rather than leaving it at the umask's default permissions.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))
+                .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))?;
+        }
+
+        Ok(())
+    }
+
+    fn load(&self) -> Result<Option<SavedSession>, Error> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&self.path)
+            .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))?;
+
+        serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))
+    }
+}
+
+/// ## Info
+/// [`QbitApi`] is the main structure of this library, providing every method to interact with the Qbittorrent WebUI API.
+pub struct QbitApi {
+    pub(crate) reqwest_client: Client,
+    pub(crate) authority: String,
+    pub(crate) cookie: Option<String>,
+    pub(crate) connection_info: ConnectionInfo,
+    session_persistence: Option<Arc<dyn SessionPersistence>>,
+}
+
+impl QbitApi {
+    /// ## Usage
+    /// logs into the Qbittorrent WebUI API at `authority` with `username`/`password`, and returns a ready-to-use [`QbitApi`].
+    pub async fn login(
+        authority: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Result<Self, Error> {
+        Self::login_with_persistence(authority, username, password, None).await
+    }
+
+    /// ## Usage
+    /// like [`QbitApi::login`], but first tries to reuse a session saved by `persistence`, only falling back
+    /// to a fresh login if none was saved. Pass a [`JsonFileSessionPersistence`] to avoid a fresh login on
+    /// every program start.
+    pub async fn login_with_persistence(
+        authority: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        persistence: Option<Arc<dyn SessionPersistence>>,
+    ) -> Result<Self, Error> {
+        let authority = authority.into();
+
+        let reqwest_client = Client::builder()
+            .build()
+            .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))?;
+
+        let mut qbit = Self {
+            reqwest_client,
+            connection_info: ConnectionInfo {
+                url: authority.clone(),
+                username: username.into(),
+                password: password.into(),
+            },
+            authority,
+            cookie: None,
+            session_persistence: persistence,
+        };
+
+        let saved = match qbit.session_persistence.clone() {
+            Some(persistence) => tokio::task::spawn_blocking(move || persistence.load())
+                .await
+                .ok()
+                .and_then(|result| result.ok())
+                .flatten(),
+            None => None,
+        };
+
+        match saved {
+            Some(session) => qbit.cookie = Some(session.cookie),
+            None => qbit.relogin().await?,
+        }
+
+        Ok(qbit)
+    }
+
+    /// ## Usage
+    /// re-runs the login this [`QbitApi`] was created with, refreshing the cached cookie (and persisting it,
+    /// if a [`SessionPersistence`] was configured). called automatically whenever a request comes back `403 Forbidden`.
+    pub(crate) async fn relogin(&mut self) -> Result<(), Error> {
+        let response = self
+            .reqwest_client
+            .post(format!("{}/api/v2/auth/login", self.connection_info.url))
+            .form(&[
+                ("username", self.connection_info.username.as_str()),
+                ("password", self.connection_info.password.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| Error::build(ErrorType::ReqwestError(Box::new(e)), None))?;
+
+        if !response.status().is_success() {
+            let code = response.status().as_u16();
+            return Err(Error::build(ErrorType::MiscError("login failed".to_string()), Some(code)));
+        }
+
+        let cookie = response
+            .cookies()
+            .find(|c| c.name() == "SID")
+            .map(|c| c.value().to_string())
+            .ok_or_else(|| Error::build(ErrorType::MiscError("login succeeded but no SID cookie was returned".to_string()), None))?;
+
+        self.cookie = Some(cookie.clone());
+
+        if let Some(persistence) = self.session_persistence.clone() {
+            let issued_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let session = SavedSession { cookie, issued_at };
+
+            tokio::task::spawn_blocking(move || persistence.save(&session))
+                .await
+                .map_err(|e| Error::build(ErrorType::MiscError(e.to_string()), None))??;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_cookie(&mut self) -> Result<String, Error> {
+        match &self.cookie {
+            Some(cookie) => Ok(cookie.clone()),
+            None => {
+                self.relogin().await?;
+                self.cookie
+                    .clone()
+                    .ok_or_else(|| Error::build(ErrorType::MiscError("login succeeded but no SID cookie was returned".to_string()), None))
+            }
+        }
+    }
+
+    /// ## Usage
+    /// sends a request built by `build_and_send`, transparently re-logging in and retrying once if the server
+    /// responds `403 Forbidden` because the cached cookie expired or got invalidated.
+    pub(crate) async fn send_authed<F>(&mut self, mut build_and_send: F) -> Result<reqwest::Response, Error>
+    where
+        F: for<'b> FnMut(&'b mut QbitApi) -> Pin<Box<dyn std::future::Future<Output = Result<reqwest::Response, Error>> + 'b>>,
+    {
+        let response = build_and_send(self).await?;
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            self.relogin().await?;
+            return build_and_send(self).await;
+        }
+
+        Ok(response)
+    }
+}
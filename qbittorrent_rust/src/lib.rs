@@ -37,4 +37,5 @@ pub use api_fns::application::app_preferences::*;
 pub use api_fns::log::logs::*;
 pub use api_fns::rss::rss::*;
 pub use api_fns::search::search::*;
+pub use api_fns::sync::maindata::*;
 pub use api_fns::torrents::{add_torrent::*, info::*, torrent_managing_misc::*, torrents::*};
\ No newline at end of file